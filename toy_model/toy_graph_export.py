@@ -0,0 +1,107 @@
+import sys
+from pathlib import Path
+from xml.etree import ElementTree as ET
+
+from toy_feature_gen import (
+    edge_index,
+    edge_weights,
+    idx2line,
+    node_features_by_idx,
+    station_line_uniq_global_lut,
+)
+
+__all__ = ["graph_nodes", "graph_edges", "to_dot", "to_graphml"]
+
+TRANSFER = "transfer"
+
+
+def graph_nodes() -> list[dict]:
+    """
+    One node per line-specific station, carrying its name, line and coordinates.
+    """
+    return [
+        {
+            "id": idx,
+            "station": station,
+            "line": line,
+            "lat": node_features_by_idx[idx][0],
+            "long": node_features_by_idx[idx][1],
+        }
+        for idx, (station, line) in station_line_uniq_global_lut.items()
+    ]
+
+
+def graph_edges() -> list[dict]:
+    """
+    Undirected edges from the (bidirectional) edge index, labelled with the line they
+    run along, or as a transfer when the two endpoints are on different lines.
+    """
+    edges = {}
+    for (src, dst), weight in zip(edge_index.t().tolist(), edge_weights.tolist()):
+        u, v = sorted((src, dst))
+        src_line = idx2line[node_features_by_idx[u][2]]
+        dst_line = idx2line[node_features_by_idx[v][2]]
+        line = src_line if src_line == dst_line else TRANSFER
+        edges[u, v] = {"source": u, "target": v, "line": line, "time": weight}
+    return [edges[key] for key in sorted(edges)]
+
+
+def to_dot() -> str:
+    """
+    Serialise as an undirected Graphviz DOT graph (readable by networkx and Gephi).
+    """
+    lines = ["graph roundel {"]
+    for node in graph_nodes():
+        label = f"{node['station']} ({node['line']})"
+        lines.append(
+            f'  {node["id"]} [label="{label}", station="{node["station"]}", '
+            f'line="{node["line"]}", lat={node["lat"]}, long={node["long"]}];'
+        )
+    for edge in graph_edges():
+        lines.append(
+            f'  {edge["source"]} -- {edge["target"]} '
+            f'[line="{edge["line"]}", time={edge["time"]}];'
+        )
+    lines.append("}")
+    return "\n".join(lines) + "\n"
+
+
+def to_graphml() -> str:
+    """
+    Serialise as GraphML with typed node and edge attribute keys.
+    """
+    root = ET.Element("graphml", xmlns="http://graphml.graphdrawing.org/xmlns")
+    keys = [
+        ("station", "node", "string"),
+        ("line", "node", "string"),
+        ("lat", "node", "double"),
+        ("long", "node", "double"),
+        ("edge_line", "edge", "string"),
+        ("time", "edge", "int"),
+    ]
+    for key_id, domain, attr_type in keys:
+        name = "line" if key_id == "edge_line" else key_id
+        ET.SubElement(
+            root,
+            "key",
+            {"id": key_id, "for": domain, "attr.name": name, "attr.type": attr_type},
+        )
+    graph = ET.SubElement(root, "graph", id="roundel", edgedefault="undirected")
+    for node in graph_nodes():
+        el = ET.SubElement(graph, "node", id=f"n{node['id']}")
+        for key_id in ("station", "line", "lat", "long"):
+            ET.SubElement(el, "data", key=key_id).text = str(node[key_id])
+    for edge in graph_edges():
+        el = ET.SubElement(
+            graph, "edge", source=f"n{edge['source']}", target=f"n{edge['target']}"
+        )
+        ET.SubElement(el, "data", key="edge_line").text = edge["line"]
+        ET.SubElement(el, "data", key="time").text = str(edge["time"])
+    ET.indent(root)
+    return ET.tostring(root, encoding="unicode", xml_declaration=True) + "\n"
+
+
+if __name__ == "__main__":
+    out_dir = Path(sys.argv[1]) if len(sys.argv) > 1 else Path.cwd()
+    (out_dir / "toy_graph.dot").write_text(to_dot())
+    (out_dir / "toy_graph.graphml").write_text(to_graphml())
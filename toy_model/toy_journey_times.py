@@ -0,0 +1,140 @@
+import heapq
+import math
+import sys
+from collections import defaultdict
+from itertools import combinations
+
+from toy_feature_gen import (
+    Stations,
+    edge_index,
+    edge_weights,
+    node_features_by_idx,
+    station_line_uniq_global_lut,
+)
+
+__all__ = [
+    "walking_edges",
+    "adjacency",
+    "station_nodes",
+    "shortest_paths",
+    "shortest_times",
+    "journey_times_from",
+    "journey_time_matrix",
+]
+
+
+# Station locations are on a unit square, so walking is costed per unit of distance.
+# Let us assume a tenth of the square (about a stop apart on a line) is a 6 minute walk
+MAX_WALK_DISTANCE = 0.15
+WALK_MINUTES_PER_UNIT = 60
+
+
+def walking_edges() -> dict[tuple[int, int], int]:
+    """
+    Walking times (in both directions) between the line-specific nodes of distinct
+    stations within walking distance, omitting stations that are adjacent on any line.
+    """
+    station_of = {idx: name for idx, (name, _) in station_line_uniq_global_lut.items()}
+    ridden = {
+        frozenset((station_of[src], station_of[dst]))
+        for src, dst in edge_index.t().tolist()
+    }
+    walks = {}
+    for u, v in combinations(station_line_uniq_global_lut, r=2):
+        stations = frozenset((station_of[u], station_of[v]))
+        if len(stations) == 1 or stations in ridden:
+            continue
+        distance = math.dist(node_features_by_idx[u][:2], node_features_by_idx[v][:2])
+        if distance <= MAX_WALK_DISTANCE:
+            minutes = max(1, round(distance * WALK_MINUTES_PER_UNIT))
+            walks[u, v] = walks[v, u] = minutes
+    return walks
+
+
+def adjacency(
+    walks: dict[tuple[int, int], int] | None = None,
+) -> dict[int, list[tuple[int, int]]]:
+    """
+    Neighbours of each line-specific station node, with the edge travel time, plus
+    any walking edges given. The edge index is already bidirectional so no reversal
+    is needed here.
+    """
+    adj = defaultdict(list)
+    for (src, dst), weight in zip(edge_index.t().tolist(), edge_weights.tolist()):
+        adj[src].append((dst, weight))
+    for (src, dst), minutes in (walks or {}).items():
+        adj[src].append((dst, minutes))
+    return adj
+
+
+def station_nodes(station: Stations) -> list[int]:
+    """
+    All line-specific node indices for a station (more than one at an interchange).
+    """
+    return [
+        idx
+        for idx, (name, _) in station_line_uniq_global_lut.items()
+        if name == station.value
+    ]
+
+
+def shortest_paths(
+    sources: list[int], walks: dict[tuple[int, int], int] | None = None
+) -> tuple[dict[int, int], dict[int, int]]:
+    """
+    Dijkstra from every source node at once, so a journey may begin on any line
+    serving the origin without paying a transfer. Returns the travel time to each
+    reachable node and the predecessor it was reached from.
+    """
+    adj = adjacency(walks=walks)
+    dist = {src: 0 for src in sources}
+    prev = {}
+    queue = [(0, src) for src in sources]
+    while queue:
+        d, node = heapq.heappop(queue)
+        if d > dist[node]:
+            continue
+        for nbr, weight in adj[node]:
+            if d + weight < dist.get(nbr, float("inf")):
+                dist[nbr] = d + weight
+                prev[nbr] = node
+                heapq.heappush(queue, (d + weight, nbr))
+    return dist, prev
+
+
+def shortest_times(sources: list[int], walk: bool = False) -> dict[int, int]:
+    """
+    Travel time from the nearest source to every reachable node.
+    """
+    dist, _ = shortest_paths(sources, walks=walking_edges() if walk else None)
+    return dist
+
+
+def journey_times_from(origin: Stations, walk: bool = False) -> dict[Stations, int]:
+    """
+    One-to-many journey times from a station to every other station, taking the
+    quickest arrival over the destination's line-specific nodes.
+    """
+    dist = shortest_times(station_nodes(origin), walk=walk)
+    return {
+        station: min(dist[idx] for idx in station_nodes(station))
+        for station in Stations
+        if station != origin
+    }
+
+
+def journey_time_matrix(walk: bool = False) -> dict[Stations, dict[Stations, int]]:
+    """
+    All-pairs journey times between stations (the diagonal is zero).
+    """
+    return {
+        origin: {origin: 0, **journey_times_from(origin, walk=walk)}
+        for origin in Stations
+    }
+
+
+if __name__ == "__main__":
+    matrix = journey_time_matrix(walk="--walk" in sys.argv)
+    print("\t".join(["", *(s.name for s in Stations)]))
+    for origin, row in matrix.items():
+        print("\t".join([origin.name, *(str(row[dest]) for dest in Stations)]))
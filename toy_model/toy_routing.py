@@ -0,0 +1,98 @@
+import sys
+from dataclasses import dataclass
+
+from toy_feature_gen import Stations, station_line_uniq_global_lut
+from toy_journey_times import shortest_paths, station_nodes, walking_edges
+
+__all__ = ["WALK", "Leg", "Route", "shortest_route", "itinerary"]
+
+WALK = "walk"
+
+
+@dataclass
+class Leg:
+    line: str
+    stations: list[str]
+    time: int
+
+
+@dataclass
+class Route:
+    legs: list[Leg]
+    time: int
+
+
+def shortest_route(
+    origin: Stations, destination: Stations, walk: bool = False
+) -> Route:
+    """
+    Quickest route between two stations as one leg per line ridden. A transfer edge
+    (a change of line within a station) ends one leg and begins the next, so its time
+    is counted between legs rather than in either of them, but is included in the
+    route's total time. With walking enabled, a walk between nearby stations is its
+    own leg.
+    """
+    walks = walking_edges() if walk else {}
+    dist, prev = shortest_paths(station_nodes(origin), walks=walks)
+    end = min(station_nodes(destination), key=lambda idx: dist[idx])
+    path = [end]
+    while path[-1] in prev:
+        path.append(prev[path[-1]])
+    path.reverse()
+    legs = []
+    starts = []
+    for i, idx in enumerate(path):
+        station, line = station_line_uniq_global_lut[idx]
+        if i and (path[i - 1], idx) in walks:
+            last = path[i - 1]
+            walked_from = station_line_uniq_global_lut[last][0]
+            legs.append(
+                Leg(line=WALK, stations=[walked_from, station], time=walks[last, idx])
+            )
+            starts.append(dist[last])
+        if legs and legs[-1].line == line:
+            legs[-1].stations.append(station)
+            legs[-1].time = dist[idx] - starts[-1]
+        else:
+            legs.append(Leg(line=line, stations=[station], time=0))
+            starts.append(dist[idx])
+    # Drop the empty ride legs either side of a walk that starts or ends the journey
+    return Route(legs=[leg for leg in legs if len(leg.stations) > 1], time=dist[end])
+
+
+def itinerary(origin: Stations, destination: Stations, walk: bool = False) -> list[str]:
+    """
+    Step-by-step directions: board, change at interchanges, walk, and alight, ending
+    with the total journey time including changes.
+    """
+    route = shortest_route(origin, destination, walk=walk)
+    legs = route.legs
+    steps = []
+    for i, leg in enumerate(legs):
+        if leg.line == WALK:
+            steps.append(f"Walk to {leg.stations[-1]} ({leg.time} min)")
+            continue
+        boarding = i == 0 or legs[i - 1].line == WALK
+        verb = "Take" if boarding else f"Change at {leg.stations[0]} to"
+        stops = len(leg.stations) - 1
+        steps.append(
+            f"{verb} the {leg.line} line to {leg.stations[-1]} "
+            f"({stops} stop{'s' * (stops != 1)}, {leg.time} min)"
+        )
+    steps.append(f"Total: {route.time} min")
+    return steps
+
+
+if __name__ == "__main__":
+    stations = ", ".join(s.name for s in Stations)
+    usage = (
+        "Usage: python toy_routing.py ORIGIN DESTINATION [--walk]\n"
+        f"Stations: {stations}"
+    )
+    args = [arg for arg in sys.argv[1:] if arg != "--walk"]
+    if len(args) != 2 or not all(name in Stations.__members__ for name in args):
+        sys.exit(usage)
+    origin, destination = (Stations[name] for name in args)
+    if origin == destination:
+        sys.exit(f"Origin and destination are both {origin.value}\n{usage}")
+    print("\n".join(itinerary(origin, destination, walk="--walk" in sys.argv)))